@@ -1,7 +1,17 @@
 #![doc = include_str!("../README.md")]
 use anyhow::{anyhow, Context};
-use colored::Colorize;
-use std::{fmt::Display, process::Command, str::FromStr};
+use colored::{Color, ColoredString, Colorize};
+use notify::{RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+    sync::mpsc,
+    time::Duration,
+};
 
 #[must_use]
 /// Runs `cargo test` with any supplied extra arguments, and returns the
@@ -29,10 +39,466 @@ pub fn get_cargo_test_output(extra_args: Vec<String>) -> String {
     String::from_utf8_lossy(&raw_output).to_string()
 }
 
+/// How a run's test results should be rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputMode {
+    /// One line per test, in the order `cargo test` reported them.
+    #[default]
+    Flat,
+    /// Grouped into a collapsible module tree, with tests nested under
+    /// their module's heading.
+    Tree,
+}
+
+/// User-configurable rendering rules, loaded from a `testdox.toml` file.
+///
+/// Any field left out of the file falls back to testdox's built-in
+/// behaviour, so an empty or missing file is equivalent to
+/// `Config::default()`.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Word replacements applied (case-insensitively) after humanizing a
+    /// test name, e.g. to preserve an acronym (`parse_url` -> `parse URL`)
+    /// or override a specific word outright.
+    #[serde(default)]
+    pub replacements: Vec<Replacement>,
+    /// Colour overrides for the pass/fail/ignored glyphs.
+    #[serde(default)]
+    pub colors: ColorConfig,
+    /// The output mode to use when the command line doesn't request one
+    /// explicitly (e.g. with `--tree`).
+    #[serde(default)]
+    pub output_mode: Option<OutputMode>,
+    /// Module path globs (matched against the prettified module path, e.g.
+    /// `"integration::*"`) to hide from the report.
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+/// A single word replacement applied by [`Config::replacements`].
+#[derive(Debug, Deserialize)]
+pub struct Replacement {
+    pub from: String,
+    pub to: String,
+}
+
+/// Colour overrides for the pass/fail/ignored glyphs, as `testdox.toml`
+/// colour names (anything [`colored::Color`] can parse, e.g. `"green"` or
+/// `"bright green"`). Unset fields keep testdox's default colours.
+#[derive(Debug, Default, Deserialize)]
+pub struct ColorConfig {
+    pub pass: Option<String>,
+    pub fail: Option<String>,
+    pub ignored: Option<String>,
+}
+
+#[must_use]
+/// Loads configuration from the nearest `testdox.toml`, discovered by
+/// walking up from the current working directory the way `cargo` locates
+/// `Cargo.toml`. Returns the default (empty) `Config` if none is found, or
+/// if it fails to parse.
+pub fn load_config() -> Config {
+    find_config_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn find_config_path() -> Option<PathBuf> {
+    let mut dir = std::env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join("testdox.toml");
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+/// Runs `cargo test`, prints the prettified results in the given `mode`,
+/// and returns whether any test failed.
+///
+/// If `slow_threshold_millis` is given, tests whose `exec_time` exceeds it
+/// are highlighted, and a "slowest tests" summary is printed at the end.
+///
+/// # Errors
+///
+/// Returns an error if `json_requested` is set but the `cargo test` output
+/// isn't libtest's JSON event stream — which happens when `-Z
+/// unstable-options` is rejected by a stable toolchain, silently producing
+/// no test output at all.
+pub fn run_and_print(
+    extra_args: Vec<String>,
+    mode: OutputMode,
+    slow_threshold_millis: Option<u64>,
+    config: &Config,
+    json_requested: bool,
+) -> anyhow::Result<bool> {
+    let output = get_cargo_test_output(extra_args);
+    if json_requested && !is_json_output(&output) {
+        return Err(anyhow!(
+            "--json produced no JSON test output; `-Z unstable-options` requires a nightly toolchain (try `cargo +nightly testdox --json`)"
+        ));
+    }
+    let results = parse_test_results(&output, config);
+    Ok(match mode {
+        OutputMode::Flat => print_flat(results, slow_threshold_millis, &config.colors),
+        OutputMode::Tree => print_tree(results, slow_threshold_millis, &config.colors),
+    })
+}
+
+/// How many of the slowest tests to list in the summary printed when
+/// `--slow` is given.
+const SLOW_SUMMARY_LIMIT: usize = 5;
+
+/// Prints one line per test and returns whether any test failed.
+fn print_flat(
+    results: Vec<TestResult>,
+    slow_threshold_millis: Option<u64>,
+    colors: &ColorConfig,
+) -> bool {
+    let mut failed = false;
+    for result in &results {
+        let status = colored_status(&result.status, colors);
+        match &result.module {
+            Some(module) => print!("{status} {} – {}", module.bright_blue(), result.name),
+            None => print!("{status} {}", result.name),
+        }
+        print!("{}", duration_suffix(result, slow_threshold_millis));
+        println!();
+        if result.status == Status::Fail {
+            failed = true;
+            if let Some(output) = &result.output {
+                print_failure_output(output, "    ");
+            }
+        }
+    }
+    if let Some(threshold) = slow_threshold_millis {
+        print_slow_summary(&results, threshold, colors);
+    }
+    failed
+}
+
+/// Renders a status glyph, using `colors`' override for its status if one
+/// is configured and valid, falling back to testdox's usual colours.
+fn colored_status(status: &Status, colors: &ColorConfig) -> ColoredString {
+    let glyph = status.glyph();
+    let override_color = match status {
+        Status::Pass => &colors.pass,
+        Status::Fail => &colors.fail,
+        Status::Ignored => &colors.ignored,
+    }
+    .as_deref()
+    .and_then(|name| name.parse::<Color>().ok());
+    match override_color {
+        Some(color) => glyph.color(color),
+        None => match status {
+            Status::Pass => glyph.bright_green(),
+            Status::Fail => glyph.bright_red(),
+            Status::Ignored => glyph.bright_yellow(),
+        },
+    }
+}
+
+/// Prints a failed test's captured output (dimmed), one line at a time,
+/// under the given `indent`.
+fn print_failure_output(output: &str, indent: &str) {
+    for line in output.lines() {
+        println!("{indent}{}", line.dimmed());
+    }
+}
+
+/// Formats a test's `(NNms)` duration suffix, dimmed, or yellow if it
+/// exceeds `slow_threshold_millis`. Returns an empty string if the test
+/// has no recorded `exec_time`.
+fn duration_suffix(result: &TestResult, slow_threshold_millis: Option<u64>) -> String {
+    let Some(exec_time) = result.exec_time else {
+        return String::new();
+    };
+    let millis = (exec_time * 1000.0).round() as u64;
+    let text = format!(" ({millis}ms)");
+    match slow_threshold_millis {
+        Some(threshold) if millis > threshold => text.yellow().to_string(),
+        _ => text.dimmed().to_string(),
+    }
+}
+
+/// Prints a "slowest tests" summary of the tests whose `exec_time` exceeds
+/// `threshold_millis`, sorted slowest-first and capped to
+/// [`SLOW_SUMMARY_LIMIT`].
+fn print_slow_summary<'a>(
+    results: impl IntoIterator<Item = &'a TestResult>,
+    threshold_millis: u64,
+    colors: &ColorConfig,
+) {
+    let mut slow: Vec<&TestResult> = results
+        .into_iter()
+        .filter(|result| {
+            result
+                .exec_time
+                .is_some_and(|secs| (secs * 1000.0).round() as u64 > threshold_millis)
+        })
+        .collect();
+    if slow.is_empty() {
+        return;
+    }
+    slow.sort_by(|a, b| b.exec_time.partial_cmp(&a.exec_time).unwrap());
+    slow.truncate(SLOW_SUMMARY_LIMIT);
+    println!("\nslowest {} test(s):", slow.len());
+    for result in slow {
+        println!(
+            "{} {}{}",
+            colored_status(&result.status, colors),
+            result.name,
+            duration_suffix(result, Some(threshold_millis))
+        );
+    }
+}
+
+/// Prints `results` grouped into a module tree and returns whether any
+/// test failed.
+fn print_tree(
+    results: Vec<TestResult>,
+    slow_threshold_millis: Option<u64>,
+    colors: &ColorConfig,
+) -> bool {
+    let mut root = ModuleNode::default();
+    for result in results {
+        root.insert(result);
+    }
+    root.print(0, slow_threshold_millis, colors);
+    if let Some(threshold) = slow_threshold_millis {
+        print_slow_summary(root.collect_tests(), threshold, colors);
+    }
+    root.any_failed()
+}
+
+/// One level of the module tree built by [`print_tree`]: the tests that
+/// belong directly to this module, plus its child modules.
+#[derive(Default)]
+struct ModuleNode {
+    tests: Vec<TestResult>,
+    children: BTreeMap<String, ModuleNode>,
+}
+
+impl ModuleNode {
+    fn insert(&mut self, result: TestResult) {
+        let segments: Vec<String> = match &result.module {
+            Some(module) => module.split("::").map(ToString::to_string).collect(),
+            None => Vec::new(),
+        };
+        self.insert_at(&segments, result);
+    }
+
+    fn insert_at(&mut self, segments: &[String], result: TestResult) {
+        match segments.split_first() {
+            None => self.tests.push(result),
+            Some((head, rest)) => self
+                .children
+                .entry(head.clone())
+                .or_default()
+                .insert_at(rest, result),
+        }
+    }
+
+    fn print(&self, depth: usize, slow_threshold_millis: Option<u64>, colors: &ColorConfig) {
+        let indent = "  ".repeat(depth);
+        for test in &self.tests {
+            println!(
+                "{indent}{} {}{}",
+                colored_status(&test.status, colors),
+                test.name,
+                duration_suffix(test, slow_threshold_millis)
+            );
+            if test.status == Status::Fail {
+                if let Some(output) = &test.output {
+                    print_failure_output(output, &"  ".repeat(depth + 1));
+                }
+            }
+        }
+        for (name, child) in &self.children {
+            println!("{indent}{}", name.bright_blue());
+            child.print(depth + 1, slow_threshold_millis, colors);
+        }
+    }
+
+    fn any_failed(&self) -> bool {
+        self.tests.iter().any(|test| test.status == Status::Fail)
+            || self.children.values().any(ModuleNode::any_failed)
+    }
+
+    /// Collects references to every test in this node and its descendants.
+    fn collect_tests(&self) -> Vec<&TestResult> {
+        let mut tests: Vec<&TestResult> = self.tests.iter().collect();
+        for child in self.children.values() {
+            tests.extend(child.collect_tests());
+        }
+        tests
+    }
+}
+
+/// Watches the crate's `src/` and `tests/` directories and re-runs
+/// `run_and_print` whenever a `.rs` file changes, clearing the screen
+/// first. Bursts of filesystem events arriving within 200ms of each
+/// other are coalesced into a single rerun. Runs until interrupted with
+/// Ctrl-C.
+///
+/// # Errors
+///
+/// Returns an error if the filesystem watcher can't be created, or can't
+/// be set to watch `src/` or `tests/`.
+pub fn watch(
+    extra_args: Vec<String>,
+    mode: OutputMode,
+    slow_threshold_millis: Option<u64>,
+    config: &Config,
+    json_requested: bool,
+) -> anyhow::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).context("creating filesystem watcher")?;
+    for dir in [Path::new("src"), Path::new("tests")] {
+        if dir.exists() {
+            watcher
+                .watch(dir, RecursiveMode::Recursive)
+                .context(format!("watching {}", dir.display()))?;
+        }
+    }
+    // A failed run is reported but doesn't end the watch.
+    let run = || {
+        if let Err(err) = run_and_print(
+            extra_args.clone(),
+            mode,
+            slow_threshold_millis,
+            config,
+            json_requested,
+        ) {
+            eprintln!("{err:?}");
+        }
+    };
+    run();
+    while let Ok(event) = rx.recv() {
+        if !is_rust_file_change(&event) {
+            continue;
+        }
+        // Coalesce a burst of events (e.g. an editor's save-then-rename) into one rerun.
+        while rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+        print!("\x1B[2J\x1B[1;1H");
+        run();
+    }
+    Ok(())
+}
+
+fn is_rust_file_change(event: &notify::Result<notify::Event>) -> bool {
+    event.as_ref().is_ok_and(|event| {
+        event
+            .paths
+            .iter()
+            .any(|path| path.extension().is_some_and(|ext| ext == "rs"))
+    })
+}
+
 #[must_use]
-/// Parses the standard output of `cargo test` into a vec of `TestResult`.
-pub fn parse_test_results(test_output: &str) -> Vec<TestResult> {
-    test_output.lines().filter_map(parse_line).collect()
+/// Parses the standard output of `cargo test` into a vec of `TestResult`,
+/// applying `config`'s word replacements and hiding any modules matched by
+/// its `ignore` globs.
+///
+/// Auto-detects whether `test_output` is libtest's plain text format or its
+/// JSON event stream (`cargo test -- -Z unstable-options --format json`),
+/// and dispatches to [`parse_line`] or [`parse_json_line`] accordingly.
+pub fn parse_test_results(test_output: &str, config: &Config) -> Vec<TestResult> {
+    let mut results: Vec<TestResult> = if is_json_output(test_output) {
+        test_output.lines().filter_map(parse_json_line).collect()
+    } else {
+        let mut results: Vec<(String, TestResult)> =
+            test_output.lines().filter_map(parse_line_raw).collect();
+        let failure_output = parse_failure_output(test_output);
+        for (raw_name, result) in &mut results {
+            if result.status == Status::Fail {
+                result.output = failure_output.get(raw_name).cloned();
+            }
+        }
+        results.into_iter().map(|(_, result)| result).collect()
+    };
+    results.retain(|result| !is_ignored(result, &config.ignore));
+    for result in &mut results {
+        result.name = apply_replacements(&result.name, &config.replacements);
+    }
+    results
+}
+
+/// Reports whether `result`'s module matches any of `ignore_globs`.
+fn is_ignored(result: &TestResult, ignore_globs: &[String]) -> bool {
+    let Some(module) = &result.module else {
+        return false;
+    };
+    ignore_globs.iter().any(|glob| glob_match(glob, module))
+}
+
+/// Matches `text` against `pattern`, which may contain a single `*`
+/// wildcard standing in for any substring.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, suffix)) => {
+            text.len() >= prefix.len() + suffix.len()
+                && text.starts_with(prefix)
+                && text.ends_with(suffix)
+        }
+    }
+}
+
+/// Replaces any whole word in `name` that case-insensitively matches a
+/// configured replacement's `from`, with its `to`.
+fn apply_replacements(name: &str, replacements: &[Replacement]) -> String {
+    if replacements.is_empty() {
+        return name.to_string();
+    }
+    name.split(' ')
+        .map(|word| {
+            replacements
+                .iter()
+                .find(|replacement| replacement.from.eq_ignore_ascii_case(word))
+                .map_or(word, |replacement| replacement.to.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses the `"---- mod::name stdout ----"` blocks from cargo test's
+/// trailing "failures:" section, keyed by the raw (unprettified)
+/// `mod::name` test path each block belongs to.
+fn parse_failure_output(test_output: &str) -> HashMap<String, String> {
+    let mut captures = HashMap::new();
+    let mut lines = test_output.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        else {
+            continue;
+        };
+        let mut output = Vec::new();
+        while let Some(next) = lines.peek() {
+            if next.is_empty() || next.starts_with("---- ") {
+                break;
+            }
+            output.push(lines.next().unwrap_or_default());
+        }
+        captures.insert(name.to_string(), output.join("\n"));
+    }
+    captures
+}
+
+/// Reports whether `test_output` looks like libtest's JSON event stream,
+/// by checking whether its first non-blank line is a JSON object.
+fn is_json_output(test_output: &str) -> bool {
+    test_output
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| line.trim_start().starts_with('{'))
 }
 
 /// Parses a line from the standard output of `cargo test`.
@@ -40,6 +506,13 @@ pub fn parse_test_results(test_output: &str) -> Vec<TestResult> {
 /// If the line represents the result of a test, returns `Some(TestResult)`,
 /// otherwise returns `None`.
 pub fn parse_line(line: impl AsRef<str>) -> Option<TestResult> {
+    parse_line_raw(line).map(|(_, result)| result)
+}
+
+/// Like [`parse_line`], but also returns the raw (unprettified) `mod::name`
+/// test path, so callers can match the result back up against cargo's
+/// "failures:" output.
+fn parse_line_raw(line: impl AsRef<str>) -> Option<(String, TestResult)> {
     let line = line.as_ref().strip_prefix("test ")?;
     if line.starts_with("result") || line.contains("(line ") {
         return None;
@@ -50,13 +523,61 @@ pub fn parse_line(line: impl AsRef<str>) -> Option<TestResult> {
         Some((module, name)) => (prettify_module(module), name),
         None => (None, test),
     };
-    Some(TestResult {
+    let result = TestResult {
         module,
         name: prettify(name),
         status: status.parse().ok()?,
+        exec_time: None,
+        output: None,
+    };
+    Some((test.to_string(), result))
+}
+
+#[must_use]
+/// Parses a line from libtest's JSON event stream
+/// (`cargo test -- -Z unstable-options --format json`).
+///
+/// If the line represents the final result of a test, returns
+/// `Some(TestResult)`. `"suite"` events (the run summary) and `"test"`
+/// events that don't yet carry a result (such as `"started"`) return
+/// `None`.
+pub fn parse_json_line(line: impl AsRef<str>) -> Option<TestResult> {
+    let event: JsonEvent = serde_json::from_str(line.as_ref()).ok()?;
+    if event.event_type != "test" {
+        return None;
+    }
+    let status = match event.event.as_str() {
+        "ok" => Status::Pass,
+        "failed" => Status::Fail,
+        "ignored" => Status::Ignored,
+        _ => return None,
+    };
+    let (module, name) = match event.name.rsplit_once("::") {
+        Some((module, name)) => (prettify_module(module), name),
+        None => (None, event.name.as_str()),
+    };
+    Some(TestResult {
+        module,
+        name: prettify(name),
+        status,
+        exec_time: event.exec_time,
+        output: event.stdout,
     })
 }
 
+/// A single line of libtest's JSON event stream, as produced by
+/// `cargo test -- -Z unstable-options --format json`.
+#[derive(Debug, Deserialize)]
+struct JsonEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    event: String,
+    #[serde(default)]
+    name: String,
+    exec_time: Option<f64>,
+    stdout: Option<String>,
+}
+
 #[must_use]
 /// Formats the name of a test function as a sentence.
 ///
@@ -103,6 +624,13 @@ pub struct TestResult {
     pub module: Option<String>,
     pub name: String,
     pub status: Status,
+    /// How long the test took to run, in seconds, if known.
+    ///
+    /// Only populated by [`parse_json_line`]; libtest's plain text output
+    /// doesn't report per-test timings.
+    pub exec_time: Option<f64>,
+    /// Captured stdout/panic output for the test, if any.
+    pub output: Option<String>,
 }
 
 impl Display for TestResult {
@@ -141,6 +669,17 @@ impl FromStr for Status {
     }
 }
 
+impl Status {
+    /// The glyph used to represent this status, without any colour applied.
+    const fn glyph(&self) -> &'static str {
+        match self {
+            Status::Pass => "✔",
+            Status::Fail => "x",
+            Status::Ignored => "?",
+        }
+    }
+}
+
 impl Display for Status {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let status = match self {
@@ -210,6 +749,8 @@ mod tests {
                     module: None,
                     name: "foo".into(),
                     status: Status::Pass,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -218,6 +759,8 @@ mod tests {
                     module: Some("foo".into()),
                     name: "does foo stuff".into(),
                     status: Status::Pass,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -226,6 +769,8 @@ mod tests {
                     module: None,
                     name: "urls correctly extracts valid urls".into(),
                     status: Status::Fail,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -234,6 +779,8 @@ mod tests {
                     module: Some("files".into()),
                     name: "files can be sorted in descending order".into(),
                     status: Status::Ignored,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -242,6 +789,8 @@ mod tests {
                     module: Some("files::test::foo".into()),
                     name: "files can be sorted in descending order".into(),
                     status: Status::Ignored,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -250,6 +799,8 @@ mod tests {
                     module: Some("files::test_foo".into()),
                     name: "files can be sorted in descending order".into(),
                     status: Status::Ignored,
+                    exec_time: None,
+                    output: None,
                 }),
             },
             Case {
@@ -262,6 +813,8 @@ mod tests {
                     module: Some("output_format".into()),
                     name: "concise expects".into(),
                     status: Status::Pass,
+                    exec_time: None,
+                    output: None,
                 }),
             },
         ]);
@@ -269,4 +822,109 @@ mod tests {
             assert_eq!(case.want, parse_line(case.line));
         }
     }
+
+    #[test]
+    fn parse_json_line_fn_returns_expected_result() {
+        struct Case {
+            line: &'static str,
+            want: Option<TestResult>,
+        }
+        let cases = Vec::from([
+            Case {
+                line: r#"{"type":"suite","event":"started","test_count":2}"#,
+                want: None,
+            },
+            Case {
+                line: r#"{"type":"test","event":"started","name":"foo::tests::does_foo_stuff"}"#,
+                want: None,
+            },
+            Case {
+                line: r#"{"type":"test","name":"foo::tests::does_foo_stuff","event":"ok","exec_time":0.0012}"#,
+                want: Some(TestResult {
+                    module: Some("foo".into()),
+                    name: "does foo stuff".into(),
+                    status: Status::Pass,
+                    exec_time: Some(0.0012),
+                    output: None,
+                }),
+            },
+            Case {
+                line: r#"{"type":"test","name":"tests::urls_correctly_extracts_valid_urls","event":"failed","exec_time":0.0003,"stdout":"thread panicked"}"#,
+                want: Some(TestResult {
+                    module: None,
+                    name: "urls correctly extracts valid urls".into(),
+                    status: Status::Fail,
+                    exec_time: Some(0.0003),
+                    output: Some("thread panicked".into()),
+                }),
+            },
+            Case {
+                line: r#"{"type":"suite","event":"ok","passed":2,"failed":0,"ignored":0,"measured":0,"filtered_out":0,"exec_time":0.01}"#,
+                want: None,
+            },
+        ]);
+        for case in cases {
+            assert_eq!(case.want, parse_json_line(case.line));
+        }
+    }
+
+    #[test]
+    fn parse_test_results_fn_attaches_captured_output_to_failed_tests() {
+        let output = "\
+running 1 test
+test tests::does_foo_stuff ... FAILED
+
+failures:
+
+---- tests::does_foo_stuff stdout ----
+thread 'tests::does_foo_stuff' panicked at src/lib.rs:1:1:
+assertion `left == right` failed
+
+failures:
+    tests::does_foo_stuff
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+";
+        let results = parse_test_results(output, &Config::default());
+        assert_eq!(
+            results,
+            Vec::from([TestResult {
+                module: None,
+                name: "does foo stuff".into(),
+                status: Status::Fail,
+                exec_time: None,
+                output: Some(
+                    "thread 'tests::does_foo_stuff' panicked at src/lib.rs:1:1:\nassertion `left == right` failed"
+                        .into()
+                ),
+            }])
+        );
+    }
+
+    #[test]
+    fn parse_test_results_fn_applies_config_replacements_and_ignore_globs() {
+        let output = "\
+test api::tests::parse_url_works ... ok
+test internal::tests::does_internal_stuff ... ok
+";
+        let config = Config {
+            replacements: Vec::from([Replacement {
+                from: "url".into(),
+                to: "URL".into(),
+            }]),
+            ignore: Vec::from(["internal".to_string()]),
+            ..Config::default()
+        };
+        let results = parse_test_results(output, &config);
+        assert_eq!(
+            results,
+            Vec::from([TestResult {
+                module: Some("api".into()),
+                name: "parse URL works".into(),
+                status: Status::Pass,
+                exec_time: None,
+                output: None,
+            }])
+        );
+    }
 }
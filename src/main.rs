@@ -1,16 +1,70 @@
-use cargo_testdox::{get_cargo_test_output, parse_test_results, Status};
+use cargo_testdox::{load_config, run_and_print, watch, OutputMode};
 
 fn main() {
-    let output = get_cargo_test_output(std::env::args().skip(2).collect());
-    let results = parse_test_results(&output);
-    let mut failed = false;
-    for result in results {
-        println!("{result}");
-        if result.status == Status::Fail {
-            failed = true;
+    let config = load_config();
+    let mut extra_args: Vec<String> = std::env::args().skip(2).collect();
+    let watching = take_flag(&mut extra_args, "--watch");
+    let mode = if take_flag(&mut extra_args, "--tree") {
+        OutputMode::Tree
+    } else {
+        config.output_mode.unwrap_or_default()
+    };
+    let slow_threshold_millis = take_value(&mut extra_args, "--slow").and_then(|v| v.parse().ok());
+    let json_requested = take_flag(&mut extra_args, "--json");
+    if json_requested {
+        extra_args.extend(["-Z", "unstable-options", "--format", "json"].map(String::from));
+    }
+    if slow_threshold_millis.is_some() && !json_requested {
+        eprintln!(
+            "note: --slow relies on per-test durations, which only the JSON backend reports; add --json (e.g. `cargo testdox --slow 100 --json`) to see them"
+        );
+    }
+    if watching {
+        if let Err(err) = watch(
+            extra_args,
+            mode,
+            slow_threshold_millis,
+            &config,
+            json_requested,
+        ) {
+            eprintln!("{err:?}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    match run_and_print(
+        extra_args,
+        mode,
+        slow_threshold_millis,
+        &config,
+        json_requested,
+    ) {
+        Ok(true) => std::process::exit(1),
+        Ok(false) => {}
+        Err(err) => {
+            eprintln!("{err:?}");
+            std::process::exit(1);
         }
     }
-    if failed {
-        std::process::exit(1);
+}
+
+/// Removes `flag` from `args` if present, returning whether it was found.
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    if let Some(pos) = args.iter().position(|arg| arg == flag) {
+        args.remove(pos);
+        true
+    } else {
+        false
     }
 }
+
+/// Removes `flag` and the value following it from `args`, if present.
+///
+/// The following argument is only taken as the value if it isn't itself a
+/// flag (i.e. doesn't start with `--`), so `--slow --bins` leaves `--bins`
+/// in place instead of silently consuming it as `--slow`'s value.
+fn take_value(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let pos = args.iter().position(|arg| arg == flag)?;
+    args.remove(pos);
+    (pos < args.len() && !args[pos].starts_with("--")).then(|| args.remove(pos))
+}